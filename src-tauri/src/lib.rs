@@ -1,25 +1,603 @@
-use std::process::{Command, Stdio};
-use tauri::Manager;
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tauri::{Emitter, Manager};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+// Must match the port websocket_server.py binds and the frontend connects to
+const BACKEND_PORT: u16 = 8000;
+const DEFAULT_BACKEND_READY_TIMEOUT_MS: u64 = 30_000;
+const BACKEND_READY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const BACKEND_HEALTH_TIMEOUT: Duration = Duration::from_secs(1);
+const DEFAULT_BACKEND_LAUNCHERS: &[&str] = &["uv", "python"];
+const DEFAULT_BACKEND_MODULE: &str = "websocket_server";
+
+// How long a stopping backend gets to exit after SIGTERM before it is killed
+// (Windows has no graceful stop for a console process, it is killed at once)
+const BACKEND_STOP_TIMEOUT: Duration = Duration::from_secs(5);
+const BACKEND_STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+const BACKEND_REAP_INTERVAL: Duration = Duration::from_secs(1);
+
+// Rotate the backend log once it grows past 1 MiB, keeping one previous file
+const BACKEND_LOG_MAX_BYTES: u64 = 1024 * 1024;
+
+/// How the Python backend is launched, read from the environment on every
+/// start so a restart picks up changes:
+///
+/// - `SENTINEL_BACKEND_LAUNCHERS`: comma-separated launchers to try in order
+///   (default `uv,python`)
+/// - `SENTINEL_BACKEND_MODULE`: Python module to run with `-m`
+///   (default `websocket_server`)
+/// - `SENTINEL_BACKEND_READY_TIMEOUT_MS`: how long to wait for the server's
+///   `/health` endpoint to answer before giving up (default 30000)
+struct BackendConfig {
+    launchers: Vec<String>,
+    module: String,
+    ready_timeout: Duration,
+}
+
+impl BackendConfig {
+    fn from_env() -> Self {
+        let launchers = env::var("SENTINEL_BACKEND_LAUNCHERS")
+            .map(|value| parse_launchers(&value))
+            .unwrap_or_default();
+
+        let module = env::var("SENTINEL_BACKEND_MODULE")
+            .ok()
+            .map(|module| module.trim().to_string())
+            .filter(|module| !module.is_empty())
+            .unwrap_or_else(|| DEFAULT_BACKEND_MODULE.to_string());
+
+        BackendConfig {
+            launchers: if launchers.is_empty() {
+                DEFAULT_BACKEND_LAUNCHERS
+                    .iter()
+                    .map(|l| l.to_string())
+                    .collect()
+            } else {
+                launchers
+            },
+            module,
+            ready_timeout: Duration::from_millis(env_or(
+                "SENTINEL_BACKEND_READY_TIMEOUT_MS",
+                DEFAULT_BACKEND_READY_TIMEOUT_MS,
+            )),
+        }
+    }
+}
+
+fn parse_launchers(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|launcher| !launcher.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn env_or<T: FromStr + std::fmt::Display>(name: &str, default: T) -> T {
+    match env::var(name) {
+        Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+            eprintln!("Ignoring invalid {} {:?}, using {}", name, value, default);
+            default
+        }),
+        Err(_) => default,
+    }
+}
+
+// Find a launcher on PATH (or at the given path), trying PATHEXT
+// extensions on Windows
+fn resolve_launcher(launcher: &str) -> Option<PathBuf> {
+    let path = Path::new(launcher);
+    if path.components().count() > 1 {
+        return path.is_file().then(|| path.to_path_buf());
+    }
+
+    let mut extensions = vec![String::new()];
+    if cfg!(windows) {
+        let pathext = env::var("PATHEXT").unwrap_or_else(|_| ".EXE;.CMD;.BAT".to_string());
+        extensions.extend(
+            pathext
+                .split(';')
+                .filter(|ext| !ext.is_empty())
+                .map(String::from),
+        );
+    }
+
+    env::split_paths(&env::var_os("PATH")?).find_map(|dir| {
+        extensions
+            .iter()
+            .map(|ext| dir.join(format!("{}{}", launcher, ext)))
+            .find(|candidate| candidate.is_file())
+    })
+}
+
+#[derive(Clone, serde::Serialize)]
+struct BackendLogLine {
+    stream: &'static str,
+    line: String,
+}
+
+/// Backend output log under the app data directory (`logs/backend.log`).
+struct BackendLog {
+    path: PathBuf,
+    file: Option<File>,
+    written: u64,
+}
+
+impl BackendLog {
+    fn open(app_handle: &tauri::AppHandle) -> Self {
+        let path = match app_handle.path().app_data_dir() {
+            Ok(dir) => dir.join("logs").join("backend.log"),
+            Err(e) => {
+                eprintln!(
+                    "Failed to resolve app data directory for backend log: {}",
+                    e
+                );
+                PathBuf::new()
+            }
+        };
+
+        let mut log = BackendLog {
+            path,
+            file: None,
+            written: 0,
+        };
+        log.reopen();
+        log.write_entry(&format!("==== Backend start {} ====", log_timestamp()));
+        log
+    }
+
+    fn reopen(&mut self) {
+        self.file = None;
+        self.written = 0;
+
+        let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) else {
+            return;
+        };
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create backend log directory {:?}: {}", dir, e);
+            return;
+        }
+
+        match OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+        {
+            Ok(file) => {
+                self.written = file.metadata().map(|m| m.len()).unwrap_or(0);
+                self.file = Some(file);
+            }
+            Err(e) => eprintln!("Failed to open backend log {:?}: {}", self.path, e),
+        }
+    }
+
+    fn write_line(&mut self, stream: &str, line: &str) {
+        if self.written >= BACKEND_LOG_MAX_BYTES {
+            self.rotate();
+        }
+
+        self.write_entry(&format!("{} [{}] {}", log_timestamp(), stream, line));
+    }
+
+    fn rotate(&mut self) {
+        // Close first, Windows won't rename a file that is still open
+        self.file = None;
+        let rotated = self.path.with_extension("log.1");
+
+        if let Err(e) = fs::rename(&self.path, &rotated) {
+            // Keep appending to the current file rather than retrying the
+            // rename on every line; it is attempted again after another
+            // BACKEND_LOG_MAX_BYTES
+            eprintln!(
+                "Failed to rotate backend log {:?} to {:?}: {}",
+                self.path, rotated, e
+            );
+            self.reopen();
+            self.written = 0;
+        } else {
+            self.reopen();
+        }
+    }
+
+    fn write_entry(&mut self, entry: &str) {
+        if let Some(file) = self.file.as_mut() {
+            if writeln!(file, "{}", entry).is_ok() {
+                self.written += entry.len() as u64 + 1;
+            }
+        }
+    }
+}
+
+// UTC wall-clock time as "YYYY-MM-DD HH:MM:SS.mmm" for backend log entries
+fn log_timestamp() -> String {
+    format_timestamp(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default(),
+    )
+}
+
+fn format_timestamp(since_epoch: Duration) -> String {
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = ((secs / 86_400) as i64, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's civil_from_days)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:03}",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+/// The Python backend, kept in managed state so it can be inspected and
+/// restarted. The lock is only held briefly; a start or restart in progress
+/// is marked by `starting`, which keeps a second caller from spawning
+/// another backend while the first one waits for readiness.
+#[derive(Default)]
+struct BackendState {
+    inner: tokio::sync::Mutex<BackendInner>,
+}
+
+#[derive(Default)]
+struct BackendInner {
+    process: Option<BackendProcess>,
+    starting: bool,
+    // Why the last start failed, kept so the frontend can find out even if
+    // it missed the "backend-error" or "backend-timeout" event
+    last_error: Option<String>,
+}
+
+struct BackendProcess {
+    child: Child,
+    launcher: String,
+}
+
+#[derive(Clone, Copy, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BackendRunState {
+    NotStarted,
+    Starting,
+    Running,
+    Exited,
+    Failed,
+}
+
+#[derive(Clone, serde::Serialize)]
+struct BackendStatus {
+    state: BackendRunState,
+    launcher: Option<String>,
+    exit_code: Option<i32>,
+    message: Option<String>,
+}
+
+impl BackendStatus {
+    fn of(inner: &mut BackendInner) -> Self {
+        let message = inner.last_error.clone();
+
+        let Some(process) = inner.process.as_mut() else {
+            let state = if inner.starting {
+                BackendRunState::Starting
+            } else if message.is_some() {
+                BackendRunState::Failed
+            } else {
+                BackendRunState::NotStarted
+            };
+            return BackendStatus {
+                state,
+                launcher: None,
+                exit_code: None,
+                message,
+            };
+        };
+
+        let (state, exit_code) = match process.child.try_wait() {
+            Ok(None) if inner.starting => (BackendRunState::Starting, None),
+            Ok(None) => (BackendRunState::Running, None),
+            Ok(Some(status)) => (BackendRunState::Exited, status.code()),
+            Err(e) => {
+                eprintln!("Failed to query Python backend status: {}", e);
+                (BackendRunState::Exited, None)
+            }
+        };
+
+        BackendStatus {
+            state,
+            launcher: Some(process.launcher.clone()),
+            exit_code,
+            message,
+        }
+    }
+}
+
+#[tauri::command]
+async fn backend_status(state: tauri::State<'_, BackendState>) -> Result<BackendStatus, String> {
+    let mut inner = state.inner.lock().await;
+    Ok(BackendStatus::of(&mut inner))
+}
+
+#[tauri::command]
+async fn restart_backend(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, BackendState>,
+) -> Result<BackendStatus, String> {
+    let old = {
+        let mut inner = state.inner.lock().await;
+        if inner.starting {
+            return Err("Python backend is already starting".to_string());
+        }
+        inner.starting = true;
+        inner.process.take()
+    };
+
+    if let Some(mut old) = old {
+        stop_backend(&mut old).await;
+    }
+
+    start_backend(&app_handle).await?;
+
+    let mut inner = state.inner.lock().await;
+    Ok(BackendStatus::of(&mut inner))
+}
+
+// Spawn the backend and wait for it to become ready, recording the outcome
+// in BackendState. The caller must have set `starting`; it is cleared here.
+async fn start_backend(app_handle: &tauri::AppHandle) -> Result<(), String> {
+    let state = app_handle.state::<BackendState>();
+    let config = BackendConfig::from_env();
+
+    // Whatever already answers on the port would pass our /health check
+    // while our own server is still importing, and ours would fail to bind
+    let result = if backend_port_in_use(BACKEND_PORT).await {
+        let message = format!(
+            "Port {} is already in use, stop whatever is listening on it and restart the backend",
+            BACKEND_PORT
+        );
+        eprintln!("{}", message);
+        let _ = app_handle.emit("backend-error", message.clone());
+        Err(message)
+    } else {
+        match start_python_backend(app_handle, &config) {
+            Ok(process) => {
+                let pid = process.child.id();
+                spawn_backend_reaper(app_handle.clone(), pid);
+                {
+                    let mut inner = state.inner.lock().await;
+                    inner.process = Some(process);
+                    inner.last_error = None;
+                }
+                let ready =
+                    wait_for_backend(app_handle, pid, BACKEND_PORT, config.ready_timeout).await;
+
+                // Don't leave a backend that never answered running behind a
+                // "failed" status
+                if ready.is_err() {
+                    let process = {
+                        let mut inner = state.inner.lock().await;
+                        match inner.process.as_ref() {
+                            Some(process) if process.child.id() == pid => inner.process.take(),
+                            _ => None,
+                        }
+                    };
+                    if let Some(mut process) = process {
+                        stop_backend(&mut process).await;
+                    }
+                }
+
+                ready
+            }
+            Err(message) => Err(message),
+        }
+    };
+
+    let mut inner = state.inner.lock().await;
+    inner.starting = false;
+    if let Err(message) = &result {
+        inner.last_error = Some(message.clone());
+    }
+
+    result
+}
+
+// Ask the backend to shut down and wait for it, escalating to a kill if it
+// hasn't exited within BACKEND_STOP_TIMEOUT
+async fn stop_backend(process: &mut BackendProcess) {
+    println!("Stopping Python backend ({})", process.launcher);
+
+    if !matches!(process.child.try_wait(), Ok(None)) {
+        return;
+    }
+
+    #[cfg(unix)]
+    {
+        terminate_backend(&process.child);
+        if wait_for_backend_exit(&mut process.child, BACKEND_STOP_TIMEOUT).await {
+            return;
+        }
+        eprintln!(
+            "Python backend did not exit within {} ms, killing it",
+            BACKEND_STOP_TIMEOUT.as_millis()
+        );
+    }
+
+    kill_backend(&process.child);
+    if !wait_for_backend_exit(&mut process.child, BACKEND_STOP_TIMEOUT).await {
+        eprintln!("Python backend did not exit after being killed");
+    }
+}
+
+// Poll rather than block in Child::wait, this runs on the async runtime
+async fn wait_for_backend_exit(child: &mut Child, timeout: Duration) -> bool {
+    let started = Instant::now();
+    loop {
+        if !matches!(child.try_wait(), Ok(None)) {
+            return true;
+        }
+        if started.elapsed() >= timeout {
+            return false;
+        }
+        tokio::time::sleep(BACKEND_STOP_POLL_INTERVAL).await;
+    }
+}
+
+// The backend is spawned as its own process group, so signalling the group
+// also reaches the Python server when it runs behind a `uv run` wrapper
+#[cfg(unix)]
+fn terminate_backend(child: &Child) {
+    signal_backend(child, libc::SIGTERM);
+}
+
+#[cfg(unix)]
+fn kill_backend(child: &Child) {
+    signal_backend(child, libc::SIGKILL);
+}
+
+#[cfg(unix)]
+fn signal_backend(child: &Child, signal: libc::c_int) {
+    // The child leads its process group, so its pid is the group id
+    if unsafe { libc::killpg(child.id() as libc::pid_t, signal) } != 0 {
+        eprintln!(
+            "Failed to signal Python backend process group: {}",
+            std::io::Error::last_os_error()
+        );
+    }
+}
+
+// taskkill /T walks the process tree, so a python.exe started by uv is
+// stopped along with it. It has to be /F: without it taskkill only posts
+// WM_CLOSE, which a windowless console process never receives.
+#[cfg(windows)]
+fn kill_backend(child: &Child) {
+    use std::os::windows::process::CommandExt;
+
+    // CREATE_NO_WINDOW, so taskkill doesn't flash a console window
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    if let Err(e) = Command::new("taskkill")
+        .arg("/PID")
+        .arg(child.id().to_string())
+        .args(["/T", "/F"])
+        .creation_flags(CREATE_NO_WINDOW)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+    {
+        eprintln!("Failed to run taskkill for Python backend: {}", e);
+    }
+}
+
+// Collect the backend's exit status as soon as it dies so it doesn't linger
+// as a zombie until the next backend_status call, and emit "backend-exited"
+// if it dies after startup. Stops once the process it watches has exited or
+// been replaced.
+fn spawn_backend_reaper(app_handle: tauri::AppHandle, pid: u32) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(BACKEND_REAP_INTERVAL).await;
+
+            let state = app_handle.state::<BackendState>();
+            let mut inner = state.inner.lock().await;
+            match inner.process.as_mut() {
+                Some(process) if process.child.id() == pid => {
+                    if let Ok(Some(status)) = process.child.try_wait() {
+                        println!("Python backend exited ({})", status);
+
+                        // An exit during startup is reported by wait_for_backend
+                        if !inner.starting {
+                            let _ = app_handle.emit(
+                                "backend-exited",
+                                format!("Python backend exited ({})", status),
+                            );
+                        }
+                        return;
+                    }
+                }
+                _ => return,
+            }
+        }
+    });
+}
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
+    let app = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(BackendState::default())
         .setup(|app| {
             let app_handle = app.handle().clone();
-            
+
             // Start the Python backend
             tauri::async_runtime::spawn(async move {
-                start_python_backend(&app_handle).await;
+                let state = app_handle.state::<BackendState>();
+                {
+                    let mut inner = state.inner.lock().await;
+
+                    // restart_backend may have got the lock first and already
+                    // started one
+                    if inner.process.is_some() || inner.starting {
+                        return;
+                    }
+                    inner.starting = true;
+                }
+
+                let _ = start_backend(&app_handle).await;
+            });
+
+            // The backend has its own process group, so Ctrl-C in a terminal
+            // no longer reaches it. Turn Ctrl-C into a normal exit instead so
+            // the backend is stopped below.
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    app_handle.exit(0);
+                }
             });
-            
+
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .invoke_handler(tauri::generate_handler![backend_status, restart_backend])
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application");
+
+    app.run(|app_handle, event| {
+        if let tauri::RunEvent::Exit = event {
+            // Don't leave the backend behind holding the port
+            let state = app_handle.state::<BackendState>();
+            tauri::async_runtime::block_on(async {
+                let process = state.inner.lock().await.process.take();
+                if let Some(mut process) = process {
+                    stop_backend(&mut process).await;
+                }
+            });
+        }
+    });
 }
 
-async fn start_python_backend(app_handle: &tauri::AppHandle) {
+fn start_python_backend(
+    app_handle: &tauri::AppHandle,
+    config: &BackendConfig,
+) -> Result<BackendProcess, String> {
     // In development, use the backend directory relative to the project root
     let backend_dir = if cfg!(debug_assertions) {
         // Development mode: use the actual backend directory
@@ -36,59 +614,272 @@ async fn start_python_backend(app_handle: &tauri::AppHandle) {
             .expect("failed to resolve resource directory");
         resource_dir.join("backend")
     };
-    
+
     println!("Starting Python backend from: {:?}", backend_dir);
-    
-    // Wait a moment for the frontend to start
-    tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-    
-    // Try to start the backend with uv first, then fallback to python
-    let mut success = false;
-    
-    // Try with uv
-    if let Ok(mut child) = Command::new("uv")
-        .current_dir(&backend_dir)
-        .arg("run")
-        .arg("python")
-        .arg("-m")
-        .arg("websocket_server")
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
-        .spawn()
-    {
-        println!("Started backend with uv");
-        success = true;
-        
-        // Monitor the process
-        tokio::task::spawn_blocking(move || {
-            let _ = child.wait();
-        });
-    } else {
-        // Fallback to direct python execution
-        match Command::new("python")
-            .current_dir(&backend_dir)
+
+    let log = Arc::new(Mutex::new(BackendLog::open(app_handle)));
+
+    // Try each launcher in order until one starts the backend
+    for launcher in &config.launchers {
+        let Some(program) = resolve_launcher(launcher) else {
+            println!("Backend launcher {} not found, skipping", launcher);
+            continue;
+        };
+
+        let mut command = Command::new(&program);
+        command.current_dir(&backend_dir);
+
+        // Give the backend its own process group so stop_backend can signal
+        // the Python server along with the launcher that started it
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        // uv has to be told to run python inside its managed environment
+        if Path::new(launcher)
+            .file_stem()
+            .is_some_and(|stem| stem == "uv")
+        {
+            command.arg("run").arg("python");
+        }
+
+        match command
             .arg("-m")
-            .arg("websocket_server")
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
+            .arg(&config.module)
+            .env("PYTHONUNBUFFERED", "1")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
             .spawn()
         {
             Ok(mut child) => {
-                println!("Started backend with Python directly");
-                success = true;
-                
-                // Monitor the process
-                tokio::task::spawn_blocking(move || {
-                    let _ = child.wait();
+                println!("Started backend with {}", launcher);
+                forward_backend_output(app_handle, &mut child, &log);
+                return Ok(BackendProcess {
+                    child,
+                    launcher: launcher.clone(),
                 });
             }
             Err(e) => {
-                eprintln!("Failed to start Python backend: {}", e);
+                eprintln!("Failed to start Python backend with {}: {}", launcher, e);
             }
         }
     }
-    
-    if !success {
-        eprintln!("Could not start Python backend with any method");
+
+    let message = format!(
+        "Could not start Python backend with any of: {}",
+        config.launchers.join(", ")
+    );
+    eprintln!("{}", message);
+    let _ = app_handle.emit("backend-error", message.clone());
+
+    Err(message)
+}
+
+#[derive(Clone, serde::Serialize)]
+struct BackendReadiness {
+    port: u16,
+    elapsed_ms: u64,
+}
+
+// Poll the websocket server's /health endpoint until it answers, emitting
+// "backend-ready" once it does or "backend-timeout" if it never comes up.
+// Polling stops early with a "backend-error" if the child exits first.
+// The child is checked around each probe so that something else already
+// answering on the port (a stale backend, another SENTINEL) is never
+// taken for ours once ours has died.
+async fn wait_for_backend(
+    app_handle: &tauri::AppHandle,
+    pid: u32,
+    port: u16,
+    timeout: Duration,
+) -> Result<(), String> {
+    let started = Instant::now();
+
+    loop {
+        if let Some(message) = backend_exit_message(app_handle, pid).await {
+            eprintln!("{}", message);
+            let _ = app_handle.emit("backend-error", message.clone());
+            return Err(message);
+        }
+
+        if backend_healthy(port).await {
+            // The child may have died while the probe was in flight
+            if let Some(message) = backend_exit_message(app_handle, pid).await {
+                eprintln!("{}", message);
+                let _ = app_handle.emit("backend-error", message.clone());
+                return Err(message);
+            }
+
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+            println!(
+                "Python backend ready on port {} after {} ms",
+                port, elapsed_ms
+            );
+            let _ = app_handle.emit("backend-ready", BackendReadiness { port, elapsed_ms });
+            return Ok(());
+        }
+
+        if started.elapsed() >= timeout {
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+            let message = format!(
+                "Python backend did not answer on port {} within {} ms",
+                port, elapsed_ms
+            );
+            eprintln!("{}", message);
+            let _ = app_handle.emit("backend-timeout", BackendReadiness { port, elapsed_ms });
+            return Err(message);
+        }
+
+        tokio::time::sleep(BACKEND_READY_POLL_INTERVAL).await;
+    }
+}
+
+// Why the backend started as `pid` is no longer running, or None while it is
+async fn backend_exit_message(app_handle: &tauri::AppHandle, pid: u32) -> Option<String> {
+    let state = app_handle.state::<BackendState>();
+    let mut inner = state.inner.lock().await;
+
+    match inner.process.as_mut() {
+        Some(process) if process.child.id() == pid => match process.child.try_wait() {
+            Ok(None) => None,
+            Ok(Some(status)) => Some(format!("Python backend exited during startup ({})", status)),
+            Err(e) => Some(format!("Failed to query Python backend status: {}", e)),
+        },
+        _ => Some("Python backend was stopped during startup".to_string()),
+    }
+}
+
+// Whether anything accepts connections on the backend port
+async fn backend_port_in_use(port: u16) -> bool {
+    let connect = tokio::net::TcpStream::connect(("127.0.0.1", port));
+    matches!(
+        tokio::time::timeout(BACKEND_HEALTH_TIMEOUT, connect).await,
+        Ok(Ok(_))
+    )
+}
+
+// GET /health on the backend and check for a 200 response
+async fn backend_healthy(port: u16) -> bool {
+    let probe = async {
+        let mut stream = tokio::net::TcpStream::connect(("127.0.0.1", port)).await?;
+        stream
+            .write_all(b"GET /health HTTP/1.1\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n")
+            .await?;
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await?;
+        Ok::<_, std::io::Error>(response)
+    };
+
+    match tokio::time::timeout(BACKEND_HEALTH_TIMEOUT, probe).await {
+        Ok(Ok(response)) => is_ok_response(&response),
+        _ => false,
+    }
+}
+
+// Whether an HTTP response's status line carries a 200
+fn is_ok_response(response: &[u8]) -> bool {
+    response.split(|&b| b == b' ').nth(1) == Some(&b"200"[..])
+}
+
+fn forward_backend_output(
+    app_handle: &tauri::AppHandle,
+    child: &mut Child,
+    log: &Arc<Mutex<BackendLog>>,
+) {
+    if let Some(stdout) = child.stdout.take() {
+        forward_stream(app_handle.clone(), stdout, "stdout", log.clone());
+    }
+    if let Some(stderr) = child.stderr.take() {
+        forward_stream(app_handle.clone(), stderr, "stderr", log.clone());
+    }
+}
+
+// Read one of the child's pipes line by line, emitting each line as a
+// "backend-log" event and appending it to the backend log file
+fn forward_stream<R: Read + Send + 'static>(
+    app_handle: tauri::AppHandle,
+    stream: R,
+    name: &'static str,
+    log: Arc<Mutex<BackendLog>>,
+) {
+    tokio::task::spawn_blocking(move || {
+        let mut reader = BufReader::new(stream);
+        let mut buf = Vec::new();
+
+        loop {
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+
+            let line = String::from_utf8_lossy(&buf)
+                .trim_end_matches(&['\r', '\n'][..])
+                .to_string();
+
+            if let Ok(mut log) = log.lock() {
+                log.write_line(name, &line);
+            }
+
+            let _ = app_handle.emit("backend-log", BackendLogLine { stream: name, line });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_timestamp_known_dates() {
+        assert_eq!(format_timestamp(Duration::ZERO), "1970-01-01 00:00:00.000");
+        assert_eq!(
+            format_timestamp(Duration::from_millis(951_782_400_000)),
+            "2000-02-29 00:00:00.000"
+        );
+        assert_eq!(
+            format_timestamp(Duration::from_millis(1_709_251_199_999)),
+            "2024-02-29 23:59:59.999"
+        );
+        assert_eq!(
+            format_timestamp(Duration::from_millis(4_102_444_799_123)),
+            "2099-12-31 23:59:59.123"
+        );
+    }
+
+    #[test]
+    fn parse_launchers_trims_and_skips_empty_entries() {
+        assert_eq!(parse_launchers(" uv , ,python3,"), vec!["uv", "python3"]);
+        assert!(parse_launchers(" , ").is_empty());
+    }
+
+    #[test]
+    fn resolve_launcher_checks_explicit_paths() {
+        let dir = env::temp_dir().join(format!("sentinel-launcher-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let launcher = dir.join("launcher");
+        File::create(&launcher).unwrap();
+
+        let resolved = resolve_launcher(launcher.to_str().unwrap());
+        assert_eq!(resolved, Some(launcher));
+        assert_eq!(
+            resolve_launcher(dir.join("missing").to_str().unwrap()),
+            None
+        );
+        assert_eq!(resolve_launcher("sentinel-no-such-launcher"), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn is_ok_response_reads_the_status_code() {
+        assert!(is_ok_response(b"HTTP/1.1 200 OK\r\n\r\n"));
+        assert!(is_ok_response(b"HTTP/1.0 200 \r\n"));
+        assert!(!is_ok_response(b"HTTP/1.1 404 Not Found\r\n\r\n"));
+        assert!(!is_ok_response(b"HTTP/1.1 2000 OK\r\n"));
+        assert!(!is_ok_response(b""));
     }
 }